@@ -8,11 +8,12 @@ use defmt::unwrap;
 /// [Link explaining it](https://www.physicsclassroom.com/class/sound/Lesson-3/Interference-and-Beats)
 use embassy_executor::Spawner;
 use embassy_rp::gpio::{self, Input, Pull};
+use embassy_rp::pwm::{Config as PwmConfig, Pwm};
 use embassy_sync::{
     blocking_mutex::raw::ThreadModeRawMutex,
-    channel::{Channel, Sender},
+    channel::{Channel, Receiver, Sender},
 };
-use embassy_time::{with_deadline, Duration, Ticker, Timer};
+use embassy_time::{with_deadline, with_timeout, Duration, Instant, Ticker, Timer};
 use gpio::{AnyPin, Level, Output};
 use {defmt_rtt as _, panic_probe as _};
 
@@ -22,6 +23,15 @@ enum LedState {
 
 static CHANNEL: Channel<ThreadModeRawMutex, LedState, 64> = Channel::new();
 
+/// A classified button gesture, as produced by [`classify_btn_gesture`].
+enum ButtonEvent {
+    SingleClick,
+    DoubleClick,
+    Hold,
+}
+
+static EVENT_CHANNEL: Channel<ThreadModeRawMutex, ButtonEvent, 64> = Channel::new();
+
 #[embassy_executor::main]
 async fn main(spawner: Spawner) {
     // Hello world via the traditional BLINK
@@ -65,6 +75,34 @@ async fn main(spawner: Spawner) {
 
     */
 
+    /*
+    // Edge-Driven Button Polling
+    let p = embassy_rp::init(Default::default());
+
+    let led = Output::new(AnyPin::from(p.PIN_25), Level::Low);
+    let external_led = Output::new(AnyPin::from(p.PIN_0), Level::Low);
+    let input_pin = Input::new(AnyPin::from(p.PIN_1), Pull::Down);
+
+    let mut error_out_one = Output::new(AnyPin::from(p.PIN_16), Level::High);
+    let mut error_out_two = Output::new(AnyPin::from(p.PIN_17), Level::High);
+
+    match spawner.spawn(edge_btn_toggle_led(input_pin, external_led)) {
+        Err(_) => {
+            error_out_one.set_high();
+        }
+        _ => (),
+    }
+
+    let dt = 100 * 1_000_000;
+    match spawner.spawn(toggle_led_no_static(led, Duration::from_nanos(dt))) {
+        Err(_) => {
+            error_out_two.set_high();
+        }
+        _ => (),
+    }
+
+    */
+
     /*
     // Channels Example
     let p = embassy_rp::init(Default::default());
@@ -128,6 +166,209 @@ async fn main(spawner: Spawner) {
         }
     }
     */
+
+    /*
+    // LED Chaser Driven By Button Gestures
+    let p = embassy_rp::init(Default::default());
+    let external_led_one = Output::new(AnyPin::from(p.PIN_0), Level::Low);
+    let external_led_two = Output::new(AnyPin::from(p.PIN_17), Level::Low);
+    let external_led_three = Output::new(AnyPin::from(p.PIN_16), Level::Low);
+
+    let btn_pin = Input::new(AnyPin::from(p.PIN_1), Pull::Down);
+
+    let mut leds = Leds::new([external_led_one, external_led_two, external_led_three]);
+
+    unwrap!(spawner.spawn(classify_btn_gesture(EVENT_CHANNEL.sender(), btn_pin)));
+
+    loop {
+        match EVENT_CHANNEL.receive().await {
+            ButtonEvent::SingleClick => leds.move_next(),
+            ButtonEvent::DoubleClick => leds.change_direction(),
+            ButtonEvent::Hold => leds.flash_all(3).await,
+        }
+    }
+    */
+
+    /*
+    // PWM Beats Demo
+    let p = embassy_rp::init(Default::default());
+    let mut config = PwmConfig::default();
+    config.top = u16::MAX;
+    config.compare_b = 0;
+    // PIN_25 is GPIO25: slice = (25/2) % 8 = 4, channel = 25 % 2 = 1 (B).
+    let pwm = Pwm::new_output_b(p.PWM_SLICE4, p.PIN_25, config.clone());
+
+    unwrap!(spawner.spawn(drive_pwm_brightness(pwm)));
+    unwrap!(spawner.spawn(pwm_beats_sweep(Duration::from_millis(20))));
+
+    loop {
+        Timer::after_secs(1).await;
+    }
+    */
+
+    /*
+    // LED Bus: Timer- Or Button-Driven Chaser Over A Const-Generic LED Count
+    let p = embassy_rp::init(Default::default());
+    let external_led_one = Output::new(AnyPin::from(p.PIN_0), Level::Low);
+    let external_led_two = Output::new(AnyPin::from(p.PIN_17), Level::Low);
+    let external_led_three = Output::new(AnyPin::from(p.PIN_16), Level::Low);
+
+    let btn_pin = Input::new(AnyPin::from(p.PIN_1), Pull::Down);
+
+    let mut bus: LedBus<'static, 3> =
+        LedBus::new([external_led_one, external_led_two, external_led_three], &CHANNEL);
+
+    // Pick one producer (or both): a fixed-period ticker, or a debounced button.
+    bus.spawn_timer_producer(spawner, Duration::from_secs(1));
+    bus.spawn_button_producer(spawner, btn_pin, Duration::from_nanos(1_000_000));
+
+    bus.run().await;
+    */
+}
+
+// LED Chaser
+
+/// A cyclic sequencer over `N` LEDs: exactly one LED is lit at `current_led`,
+/// and `move_next`/`change_direction` walk that index forward or backward.
+struct Leds<'a, const N: usize> {
+    leds: [Output<'a>; N],
+    current_led: usize,
+    direction: i8,
+}
+
+impl<'a, const N: usize> Leds<'a, N> {
+    pub fn new(leds: [Output<'a>; N]) -> Self {
+        let mut this = Self {
+            leds,
+            current_led: 0,
+            direction: 1,
+        };
+        this.leds[0].set_high();
+        this
+    }
+
+    pub fn move_next(&mut self) {
+        self.leds[self.current_led].set_low();
+        self.current_led = if self.direction > 0 {
+            (self.current_led + 1) % N
+        } else {
+            (self.current_led + N - 1) % N
+        };
+        self.leds[self.current_led].set_high();
+    }
+
+    pub fn change_direction(&mut self) {
+        self.direction = -self.direction;
+    }
+
+    pub async fn flash_all(&mut self, times: usize) {
+        for _ in 0..times {
+            for led in self.leds.iter_mut() {
+                led.set_high();
+            }
+            Timer::after_millis(100).await;
+            for led in self.leds.iter_mut() {
+                led.set_low();
+            }
+            Timer::after_millis(100).await;
+        }
+        self.leds[self.current_led].set_high();
+    }
+}
+
+// PWM Brightness
+
+static BRIGHTNESS_CHANNEL: Channel<ThreadModeRawMutex, f32, 64> = Channel::new();
+
+/// Set the LED brightness to `fraction` (clamped to `0.0..=1.0`) by sending it
+/// to [`BRIGHTNESS_CHANNEL`], where [`drive_pwm_brightness`] turns it into a
+/// duty cycle.
+async fn set_brightness(fraction: f32) {
+    BRIGHTNESS_CHANNEL.send(fraction.clamp(0.0, 1.0)).await;
+}
+
+#[embassy_executor::task]
+async fn drive_pwm_brightness(mut pwm: Pwm<'static>) {
+    loop {
+        let fraction = BRIGHTNESS_CHANNEL.receive().await;
+        let mut config = PwmConfig::default();
+        config.top = u16::MAX;
+        let compare = (fraction * u16::MAX as f32) as u16;
+        // Set both channels so this driver works regardless of which output
+        // (A, B, or both) the caller's `Pwm` was configured for.
+        config.compare_a = compare;
+        config.compare_b = compare;
+        pwm.set_config(&config);
+    }
+}
+
+/// Ramps the brightness from 0.0 to 1.0 and back down again (a triangle
+/// wave) through [`set_brightness`], stepping once per `step` tick.
+#[embassy_executor::task]
+async fn pwm_beats_sweep(step: Duration) {
+    const INCREMENT: f32 = 1.0 / 64.0;
+
+    let mut duty: f32 = 0.0;
+    let mut direction: f32 = 1.0;
+    let mut ticker = Ticker::every(step);
+    loop {
+        set_brightness(duty).await;
+
+        duty += direction * INCREMENT;
+        if duty >= 1.0 {
+            duty = 1.0;
+            direction = -1.0;
+        } else if duty <= 0.0 {
+            duty = 0.0;
+            direction = 1.0;
+        }
+
+        ticker.next().await;
+    }
+}
+
+/// Ties an `N`-LED [`Leds`] sequencer to the fixed `Channel<ThreadModeRawMutex,
+/// LedState, 64>` type: producer tasks (timer-driven, button-driven, ...) are
+/// spawned against [`LedBus::sender`], and [`LedBus::run`] consumes
+/// `LedState::Toggle` messages to walk the chase. Only the LED count `N` is
+/// generalized here; the channel type and capacity are still fixed.
+struct LedBus<'a, const N: usize> {
+    leds: Leds<'a, N>,
+    sender: Sender<'static, ThreadModeRawMutex, LedState, 64>,
+    receiver: Receiver<'static, ThreadModeRawMutex, LedState, 64>,
+}
+
+impl<'a, const N: usize> LedBus<'a, N> {
+    pub fn new(
+        leds: [Output<'a>; N],
+        channel: &'static Channel<ThreadModeRawMutex, LedState, 64>,
+    ) -> Self {
+        Self {
+            leds: Leds::new(leds),
+            sender: channel.sender(),
+            receiver: channel.receiver(),
+        }
+    }
+
+    pub fn sender(&self) -> Sender<'static, ThreadModeRawMutex, LedState, 64> {
+        self.sender
+    }
+
+    pub fn spawn_timer_producer(&self, spawner: Spawner, period: Duration) {
+        unwrap!(spawner.spawn(toggle_led_sequence(self.sender(), period)));
+    }
+
+    pub fn spawn_button_producer(&self, spawner: Spawner, btn: Input<'static>, poll: Duration) {
+        unwrap!(spawner.spawn(poll_btn_with_state(self.sender(), btn, poll)));
+    }
+
+    pub async fn run(&mut self) {
+        loop {
+            match self.receiver.receive().await {
+                LedState::Toggle => self.leds.move_next(),
+            }
+        }
+    }
 }
 
 // Messaging Tasks
@@ -167,6 +408,20 @@ impl<'a> Debouncer<'a> {
             }
         }
     }
+
+    /// The instantaneous level, without waiting for an edge.
+    pub fn level(&self) -> Level {
+        self.input.get_level()
+    }
+
+    /// Waits for the next edge, lets it settle, then returns the level —
+    /// unlike [`Debouncer::debounce`], this doesn't re-loop if the settled
+    /// level matches where it started.
+    pub async fn settled_level(&mut self) -> Level {
+        self.input.wait_for_any_edge().await;
+        Timer::after(self.debounce).await;
+        self.input.get_level()
+    }
 }
 
 #[embassy_executor::task]
@@ -188,6 +443,46 @@ async fn poll_btn_with_state(
     }
 }
 
+// Gesture Recognizing Button Task
+
+const DOUBLE_CLICK_WINDOW: Duration = Duration::from_millis(250);
+const HOLD_THRESHOLD: Duration = Duration::from_secs(1);
+
+#[embassy_executor::task]
+async fn classify_btn_gesture(
+    control: Sender<'static, ThreadModeRawMutex, ButtonEvent, 64>,
+    btn: Input<'static>,
+) {
+    let mut debounce_btn = Debouncer::new(btn, Duration::from_millis(20));
+    loop {
+        // Button down
+        debounce_btn.debounce().await;
+
+        // Race the release against the hold threshold.
+        match with_deadline(Instant::now() + HOLD_THRESHOLD, debounce_btn.debounce()).await {
+            Err(_) => {
+                // Still held once the deadline elapsed: it's a hold.
+                control.send(ButtonEvent::Hold).await;
+                // Button up
+                debounce_btn.debounce().await;
+            }
+            Ok(_) => {
+                // Released in time; see if a second press follows within the window.
+                match with_timeout(DOUBLE_CLICK_WINDOW, debounce_btn.debounce()).await {
+                    Ok(_) => {
+                        // Second press detected; consume its release.
+                        debounce_btn.debounce().await;
+                        control.send(ButtonEvent::DoubleClick).await;
+                    }
+                    Err(_) => {
+                        control.send(ButtonEvent::SingleClick).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
 // Basic Button Tasks
 #[embassy_executor::task]
 async fn poll_btn_toggle_led(btn: Input<'static>, mut led: Output<'static>) {
@@ -203,6 +498,20 @@ async fn poll_btn_toggle_led(btn: Input<'static>, mut led: Output<'static>) {
     }
 }
 
+#[embassy_executor::task]
+async fn edge_btn_toggle_led(btn: Input<'static>, mut led: Output<'static>) {
+    // Read the level once per edge instead of polling on a Ticker, so the
+    // executor only wakes when the button actually changes state.
+    let mut debounce_btn = Debouncer::new(btn, Duration::from_millis(20));
+    loop {
+        match debounce_btn.level() {
+            Level::High => led.set_high(),
+            Level::Low => led.set_low(),
+        }
+        debounce_btn.settled_level().await;
+    }
+}
+
 #[embassy_executor::task]
 async fn toggle_led_no_static(mut led: Output<'static>, delay: Duration) {
     let mut ticker = Ticker::every(delay);